@@ -0,0 +1,88 @@
+//! An allocation-free set of byte values, used to constrain which flips an iterator yields.
+
+use std::ops::{Range, RangeInclusive};
+
+/// A set of `u8` values, stored as four 64-bit words so that membership tests and inserts are
+/// both O(1) without allocating.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    /// An empty set.
+    pub const fn new() -> Self {
+        ByteSet([0; 4])
+    }
+
+    /// Adds `byte` to the set.
+    pub fn insert(&mut self, byte: u8) {
+        let (word, bit) = Self::locate(byte);
+        self.0[word] |= 1 << bit;
+    }
+
+    /// Returns whether `byte` is a member of the set.
+    pub fn contains(&self, byte: u8) -> bool {
+        let (word, bit) = Self::locate(byte);
+        self.0[word] & (1 << bit) != 0
+    }
+
+    fn locate(byte: u8) -> (usize, u32) {
+        (byte as usize / 64, byte as u32 % 64)
+    }
+}
+
+impl From<&[u8]> for ByteSet {
+    fn from(bytes: &[u8]) -> Self {
+        bytes.iter().copied().collect()
+    }
+}
+
+impl From<Range<u8>> for ByteSet {
+    fn from(range: Range<u8>) -> Self {
+        range.collect()
+    }
+}
+
+impl From<RangeInclusive<u8>> for ByteSet {
+    fn from(range: RangeInclusive<u8>) -> Self {
+        range.collect()
+    }
+}
+
+impl FromIterator<u8> for ByteSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut set = ByteSet::new();
+        for byte in iter {
+            set.insert(byte);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let set: ByteSet = b"ab".as_slice().into();
+        assert!(set.contains(b'a'));
+        assert!(set.contains(b'b'));
+        assert!(!set.contains(b'c'));
+    }
+
+    #[test]
+    fn from_range() {
+        let set: ByteSet = (b'a'..=b'z').into();
+        assert!(set.contains(b'm'));
+        assert!(!set.contains(b'M'));
+        assert!(!set.contains(0));
+    }
+
+    #[test]
+    fn covers_every_byte_value() {
+        let set: ByteSet = (0..=255).collect();
+        for byte in 0..=255u8 {
+            assert!(set.contains(byte));
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Extension-trait surface for byte slices and string types.
+//!
+//! This mirrors the style [`bstr`](https://docs.rs/bstr) uses for its `ByteSlice` trait: instead
+//! of calling free functions, `.bitflips()` and friends can be called directly on `[u8]`, `str`,
+//! and their owned forms, which chains naturally with slicing and other adapter methods.
+
+use crate::{ascii_bytes, bytes, utf8, ByteIterator, StringIterator};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for [u8] {}
+    impl Sealed for Vec<u8> {}
+    impl Sealed for str {}
+    impl Sealed for String {}
+}
+
+/// Extension methods for generating bitflips directly from byte and string types.
+///
+/// This trait is sealed: it can only be implemented by this crate, and is implemented for
+/// `[u8]`, `Vec<u8>`, `str`, and `String`.
+pub trait BitFlip: sealed::Sealed {
+    /// Flips each bit within `self` in turn. Equivalent to [`crate::bytes`].
+    fn bitflips(&self) -> ByteIterator;
+
+    /// Flips each of the low seven bits within `self` in turn. Equivalent to
+    /// [`crate::ascii_bytes`].
+    fn ascii_bitflips(&self) -> ByteIterator;
+
+    /// Flips each bit within `self` in turn, keeping only the variants that are valid UTF-8.
+    /// Equivalent to [`crate::utf8`].
+    fn utf8_bitflips(&self) -> StringIterator;
+}
+
+impl BitFlip for [u8] {
+    fn bitflips(&self) -> ByteIterator {
+        bytes(self)
+    }
+
+    fn ascii_bitflips(&self) -> ByteIterator {
+        ascii_bytes(self)
+    }
+
+    fn utf8_bitflips(&self) -> StringIterator {
+        StringIterator(bytes(self))
+    }
+}
+
+impl BitFlip for Vec<u8> {
+    fn bitflips(&self) -> ByteIterator {
+        bytes(self)
+    }
+
+    fn ascii_bitflips(&self) -> ByteIterator {
+        ascii_bytes(self)
+    }
+
+    fn utf8_bitflips(&self) -> StringIterator {
+        StringIterator(bytes(self))
+    }
+}
+
+impl BitFlip for str {
+    fn bitflips(&self) -> ByteIterator {
+        bytes(self.as_bytes())
+    }
+
+    fn ascii_bitflips(&self) -> ByteIterator {
+        ascii_bytes(self.as_bytes())
+    }
+
+    fn utf8_bitflips(&self) -> StringIterator {
+        utf8(self)
+    }
+}
+
+impl BitFlip for String {
+    fn bitflips(&self) -> ByteIterator {
+        bytes(self.as_bytes())
+    }
+
+    fn ascii_bitflips(&self) -> ByteIterator {
+        ascii_bytes(self.as_bytes())
+    }
+
+    fn utf8_bitflips(&self) -> StringIterator {
+        utf8(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn byte_slice_matches_free_functions() {
+        let via_trait: BTreeSet<Vec<u8>> = b"abc".bitflips().collect();
+        let via_fn: BTreeSet<Vec<u8>> = bytes(b"abc").collect();
+        assert_eq!(via_trait, via_fn);
+
+        let via_trait: BTreeSet<Vec<u8>> = b"abc".ascii_bitflips().collect();
+        let via_fn: BTreeSet<Vec<u8>> = ascii_bytes(b"abc").collect();
+        assert_eq!(via_trait, via_fn);
+    }
+
+    #[test]
+    fn str_matches_free_functions() {
+        let via_trait: BTreeSet<String> = "abc".utf8_bitflips().collect();
+        let via_fn: BTreeSet<String> = utf8("abc").collect();
+        assert_eq!(via_trait, via_fn);
+    }
+}
@@ -0,0 +1,227 @@
+//! Streaming bitflips over a [`BufRead`], for feeding large corpora into a fuzzing harness
+//! without materializing the whole input up front the way [`ByteIterator`] requires.
+
+use std::io::{self, BufRead};
+
+use crate::{ascii_bytes, bytes, ByteIterator, ByteSet};
+
+/// Extension trait adding streaming bitflip adapters to any [`BufRead`].
+pub trait BufReadExt: BufRead {
+    /// Reads `self` one line at a time and yields the bitflipped variants of each line, without
+    /// ever buffering the entire input.
+    ///
+    /// Lines are split the same way as [`BufRead::read_line`]; the trailing `\n` (and `\r\n`) is
+    /// stripped before flipping.
+    fn bitflip_lines(self) -> BitflipLines<Self>
+    where
+        Self: Sized,
+    {
+        BitflipLines::new(self)
+    }
+
+    /// Reads `self` in fixed-size chunks and yields the bitflipped variants of each chunk,
+    /// without ever buffering the entire input.
+    ///
+    /// Every chunk is `chunk_size` bytes except possibly the last, which may be shorter.
+    fn bitflip_chunks(self, chunk_size: usize) -> BitflipChunks<Self>
+    where
+        Self: Sized,
+    {
+        BitflipChunks::new(self, chunk_size)
+    }
+}
+
+impl<R: BufRead> BufReadExt for R {}
+
+fn flips_for(chunk: &[u8], ascii: bool, allowed: &Option<ByteSet>) -> ByteIterator {
+    let mut iter = if ascii {
+        ascii_bytes(chunk)
+    } else {
+        bytes(chunk)
+    };
+    if let Some(set) = allowed {
+        iter = iter.allowed_bytes(*set);
+    }
+    iter
+}
+
+/// Iterator returned by [`BufReadExt::bitflip_lines`].
+pub struct BitflipLines<R> {
+    reader: R,
+    ascii: bool,
+    allowed: Option<ByteSet>,
+    buf: Vec<u8>,
+    current: Option<ByteIterator>,
+}
+
+impl<R: BufRead> BitflipLines<R> {
+    fn new(reader: R) -> Self {
+        BitflipLines {
+            reader,
+            ascii: false,
+            allowed: None,
+            buf: Vec::new(),
+            current: None,
+        }
+    }
+
+    /// Restricts output to ASCII-only bit flips, matching [`crate::ascii_bytes`].
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    /// Restricts output to flips whose mutated byte is a member of `set`, matching
+    /// [`ByteIterator::allowed_bytes`].
+    pub fn allowed_bytes(mut self, set: impl Into<ByteSet>) -> Self {
+        self.allowed = Some(set.into());
+        self
+    }
+}
+
+impl<R: BufRead> Iterator for BitflipLines<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(iter) = &mut self.current {
+                if let Some(flipped) = iter.next() {
+                    return Some(Ok(flipped));
+                }
+                self.current = None;
+            }
+
+            self.buf.clear();
+            match self.reader.read_until(b'\n', &mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    if self.buf.last() == Some(&b'\n') {
+                        self.buf.pop();
+                        if self.buf.last() == Some(&b'\r') {
+                            self.buf.pop();
+                        }
+                    }
+                    self.current = Some(flips_for(&self.buf, self.ascii, &self.allowed));
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`BufReadExt::bitflip_chunks`].
+pub struct BitflipChunks<R> {
+    reader: R,
+    chunk_size: usize,
+    ascii: bool,
+    allowed: Option<ByteSet>,
+    current: Option<ByteIterator>,
+}
+
+impl<R: BufRead> BitflipChunks<R> {
+    fn new(reader: R, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than zero");
+        BitflipChunks {
+            reader,
+            chunk_size,
+            ascii: false,
+            allowed: None,
+            current: None,
+        }
+    }
+
+    /// Restricts output to ASCII-only bit flips, matching [`crate::ascii_bytes`].
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    /// Restricts output to flips whose mutated byte is a member of `set`, matching
+    /// [`ByteIterator::allowed_bytes`].
+    pub fn allowed_bytes(mut self, set: impl Into<ByteSet>) -> Self {
+        self.allowed = Some(set.into());
+        self
+    }
+
+    fn read_chunk(&mut self) -> io::Result<Vec<u8>> {
+        let mut chunk = vec![0u8; self.chunk_size];
+        let mut filled = 0;
+        while filled < chunk.len() {
+            match self.reader.read(&mut chunk[filled..])? {
+                0 => break,
+                n => filled += n,
+            }
+        }
+        chunk.truncate(filled);
+        Ok(chunk)
+    }
+}
+
+impl<R: BufRead> Iterator for BitflipChunks<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(iter) = &mut self.current {
+                if let Some(flipped) = iter.next() {
+                    return Some(Ok(flipped));
+                }
+                self.current = None;
+            }
+
+            match self.read_chunk() {
+                Ok(chunk) if chunk.is_empty() => return None,
+                Ok(chunk) => self.current = Some(flips_for(&chunk, self.ascii, &self.allowed)),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn bitflip_lines_matches_in_memory() {
+        let input = b"ab\ncd\n".as_slice();
+        let results: io::Result<BTreeSet<Vec<u8>>> = input.bitflip_lines().collect();
+        let results = results.unwrap();
+
+        let expected: BTreeSet<Vec<u8>> = bytes(b"ab").chain(bytes(b"cd")).collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn bitflip_lines_strips_crlf() {
+        let input = b"ab\r\n".as_slice();
+        let results: Vec<Vec<u8>> = input.bitflip_lines().map(Result::unwrap).collect();
+        assert_eq!(results.len(), 8 * 2);
+    }
+
+    #[test]
+    fn bitflip_lines_respects_ascii_and_allowed_bytes() {
+        let set: ByteSet = (0x20..0x7f).into();
+        let input = b"a\n".as_slice();
+        let results: Vec<Vec<u8>> = input
+            .bitflip_lines()
+            .ascii()
+            .allowed_bytes(set)
+            .map(Result::unwrap)
+            .collect();
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| set.contains(r[0])));
+    }
+
+    #[test]
+    fn bitflip_chunks_splits_on_fixed_size() {
+        let input = b"abcd".as_slice();
+        let results: io::Result<BTreeSet<Vec<u8>>> = input.bitflip_chunks(2).collect();
+        let results = results.unwrap();
+
+        let expected: BTreeSet<Vec<u8>> = bytes(b"ab").chain(bytes(b"cd")).collect();
+        assert_eq!(results, expected);
+    }
+}
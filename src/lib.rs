@@ -22,6 +22,22 @@
 //! by Zack Allen.
 //!
 //! [blip]: https://pypi.org/project/blip/
+//!
+//! [`bytes`], [`ascii_bytes`] and [`utf8`] are also available as methods on `[u8]`, `str`, and
+//! their owned forms via the [`BitFlip`] extension trait, e.g. `b"ab".ascii_bitflips()` or
+//! `"ab".utf8_bitflips()`. [`ascii_str`] has no method form.
+
+mod byteset;
+mod ext;
+mod io;
+mod lossy;
+mod os;
+
+pub use byteset::ByteSet;
+pub use ext::BitFlip;
+pub use io::{BitflipChunks, BitflipLines, BufReadExt};
+pub use lossy::{utf8_lossy, Utf8Lossy, Utf8LossyIterator};
+pub use os::{os_str, path, OsStrIterator, PathIterator};
 
 /// Flips each bit within the ASCII byte string in turn.
 ///
@@ -33,6 +49,8 @@ pub fn ascii_bytes(input: &[u8]) -> ByteIterator {
         pos: 0,
         bit: 0,
         max: 7,
+        allowed: None,
+        frozen: None,
     }
 }
 
@@ -53,6 +71,8 @@ pub fn bytes(input: &[u8]) -> ByteIterator {
         pos: 0,
         bit: 0,
         max: 8,
+        allowed: None,
+        frozen: None,
     }
 }
 
@@ -69,32 +89,78 @@ pub struct ByteIterator {
     pos: usize,
     bit: usize,
     max: usize,
+    allowed: Option<ByteSet>,
+    frozen: Option<Vec<bool>>,
+}
+
+impl ByteIterator {
+    /// Restricts the iterator to only yield flips whose mutated byte is a member of `set`.
+    ///
+    /// This lets callers generate e.g. only printable-ASCII mutations without filtering the
+    /// full `8 * len` stream after the fact.
+    pub fn allowed_bytes(mut self, set: impl Into<ByteSet>) -> Self {
+        self.allowed = Some(set.into());
+        self
+    }
+
+    /// Keeps the byte offsets in `positions` from ever being flipped.
+    ///
+    /// This is useful for fuzzing a field within a larger structure while leaving framing or
+    /// delimiter bytes intact.
+    pub fn frozen_positions(mut self, positions: impl IntoIterator<Item = usize>) -> Self {
+        let mut frozen = vec![false; self.input.len()];
+        for pos in positions {
+            if let Some(slot) = frozen.get_mut(pos) {
+                *slot = true;
+            }
+        }
+        self.frozen = Some(frozen);
+        self
+    }
+
+    fn advance(&mut self) {
+        self.bit += 1;
+        if self.bit >= self.max {
+            self.pos += 1;
+            self.bit = 0;
+        }
+    }
 }
 
 impl Iterator for ByteIterator {
     type Item = Vec<u8>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos >= self.input.len() {
-            None
-        } else {
+        while self.pos < self.input.len() {
+            let pos = self.pos;
+            let bit = self.bit;
+            self.advance();
+
+            if let Some(frozen) = &self.frozen {
+                if frozen[pos] {
+                    continue;
+                }
+            }
+
             let mut output = self.input.clone();
-            output[self.pos] ^= 1 << self.bit;
+            output[pos] ^= 1 << bit;
 
-            self.bit += 1;
-            if self.bit >= self.max {
-                self.pos += 1;
-                self.bit = 0;
+            if let Some(allowed) = &self.allowed {
+                if !allowed.contains(output[pos]) {
+                    continue;
+                }
             }
 
-            Some(output)
+            return Some(output);
         }
+
+        None
     }
 }
 
 /// Iterator returned by functions that yield [`String`].
 #[derive(Clone, Debug)]
-pub struct StringIterator(ByteIterator);
+pub struct StringIterator(pub(crate) ByteIterator);
 
 impl Iterator for StringIterator {
     type Item = String;
@@ -189,4 +255,19 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_allowed_bytes() {
+        let set: ByteSet = (0x20..0x7f).into();
+        let results: Vec<Vec<u8>> = bytes(b"a").allowed_bytes(set).collect();
+        assert!(results.iter().all(|r| set.contains(r[0])));
+        assert!(!results.is_empty());
+    }
+
+    #[test]
+    fn test_frozen_positions() {
+        let results: BTreeSet<Vec<u8>> = bytes(b"ab").frozen_positions([0]).collect();
+        assert_eq!(results.len(), 8);
+        assert!(results.iter().all(|r| r[0] == b'a'));
+    }
 }
@@ -0,0 +1,68 @@
+//! Lossy UTF-8 flipping, which never drops a flip just because it isn't valid UTF-8.
+
+use std::borrow::Cow;
+
+use crate::{bytes, ByteIterator};
+
+/// Flips each bit within the given string, decoding each result with [`String::from_utf8_lossy`]
+/// rather than discarding the flips that aren't valid UTF-8 on their own.
+///
+/// Unlike [`crate::utf8`], the returned iterator yields all `8 * input.len()` variants.
+pub fn utf8_lossy(input: &str) -> Utf8LossyIterator {
+    Utf8LossyIterator(bytes(input.as_bytes()))
+}
+
+/// A single lossily-decoded flip, yielded by [`Utf8LossyIterator`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Utf8Lossy {
+    /// The flipped bytes, decoded as UTF-8 with invalid sequences replaced by U+FFFD.
+    pub value: String,
+    /// Whether decoding `value` required substituting at least one U+FFFD replacement character,
+    /// i.e. whether the flip produced a byte sequence that wasn't valid UTF-8 on its own.
+    pub replaced: bool,
+}
+
+/// Iterator returned by [`utf8_lossy`].
+#[derive(Clone, Debug)]
+pub struct Utf8LossyIterator(ByteIterator);
+
+impl Iterator for Utf8LossyIterator {
+    type Item = Utf8Lossy;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|flipped| match String::from_utf8_lossy(&flipped) {
+                Cow::Borrowed(s) => Utf8Lossy {
+                    value: s.to_owned(),
+                    replaced: false,
+                },
+                Cow::Owned(value) => Utf8Lossy {
+                    value,
+                    replaced: true,
+                },
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yields_every_flip() {
+        let results: Vec<Utf8Lossy> = utf8_lossy("é").collect();
+        assert_eq!(results.len(), 8 * "é".len());
+        assert!(results.iter().any(|r| r.replaced));
+        assert!(results.iter().any(|r| !r.replaced));
+    }
+
+    #[test]
+    fn reports_no_replacement_for_valid_flips() {
+        let valid: Vec<Utf8Lossy> = utf8_lossy("ab").filter(|r| !r.replaced).collect();
+        for result in &valid {
+            assert!(!result.value.contains('\u{FFFD}'));
+        }
+        assert!(!valid.is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+//! Bitflipping for `OsStr` and `Path`, for fuzzing filesystem and argument-parsing code that a
+//! pure-`str` API can't reach.
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use crate::{bytes, ByteIterator};
+
+/// Flips each bit within the given `OsStr`.
+///
+/// On Unix, this operates on the raw underlying bytes via
+/// [`OsStrExt`](std::os::unix::ffi::OsStrExt), so every flip is lossless. On platforms without a
+/// lossless byte view of `OsStr` (e.g. Windows), this falls back to a lossy UTF-8 round-trip, so
+/// some flips may collapse onto the same `OsString` or lose information the platform's native
+/// encoding would have preserved.
+pub fn os_str(input: &OsStr) -> OsStrIterator {
+    OsStrIterator(bytes(&to_bytes(input)))
+}
+
+/// Flips each bit within the given `Path`.
+///
+/// See [`os_str`] for the platform caveats this inherits.
+pub fn path(input: &Path) -> PathIterator {
+    PathIterator(os_str(input.as_os_str()))
+}
+
+#[cfg(unix)]
+fn to_bytes(input: &OsStr) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    input.as_bytes().to_vec()
+}
+
+#[cfg(not(unix))]
+fn to_bytes(input: &OsStr) -> Vec<u8> {
+    input.to_string_lossy().into_owned().into_bytes()
+}
+
+#[cfg(unix)]
+fn from_bytes(input: Vec<u8>) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(input)
+}
+
+#[cfg(not(unix))]
+fn from_bytes(input: Vec<u8>) -> OsString {
+    OsString::from(String::from_utf8_lossy(&input).into_owned())
+}
+
+/// Iterator returned by [`os_str`].
+#[derive(Clone, Debug)]
+pub struct OsStrIterator(ByteIterator);
+
+impl Iterator for OsStrIterator {
+    type Item = OsString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(from_bytes)
+    }
+}
+
+/// Iterator returned by [`path`].
+#[derive(Clone, Debug)]
+pub struct PathIterator(OsStrIterator);
+
+impl Iterator for PathIterator {
+    type Item = PathBuf;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(PathBuf::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn os_str_yields_every_flip() {
+        let input = OsStr::new("ab");
+        let results: Vec<OsString> = os_str(input).collect();
+        assert_eq!(results.len(), 8 * 2);
+    }
+
+    #[test]
+    fn path_yields_every_flip() {
+        let input = Path::new("ab");
+        let results: Vec<PathBuf> = path(input).collect();
+        assert_eq!(results.len(), 8 * 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str_is_lossless_on_unix() {
+        use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+        let input = OsStr::from_bytes(b"a\xffc");
+        let results: Vec<OsString> = os_str(input).collect();
+        assert!(results
+            .iter()
+            .any(|r| OsStringExt::into_vec(r.clone()) == b"a\xfec"));
+    }
+}